@@ -2,6 +2,28 @@
 
 #[macro_export]
 macro_rules! nested_struct {
+    // [MAIN + ACCESSORS] Same as [MAIN], but also generates deep field-path accessor methods
+    // (`fn a_b_value(&self)` / `fn a_b_value_mut(&mut self)`) for every leaf field reachable
+    // by walking through nested struct fields.
+    (
+        $(#[$meta:meta])*
+        @accessors
+        $vis:vis struct $name:ident {
+            $($fields:tt)*
+        }
+    ) => {
+        nested_struct! {
+            $(#[$meta])*
+            $vis struct $name {
+                $($fields)*
+            }
+        }
+
+        nested_struct! {
+            @accessors $name [] { $($fields)* }
+        }
+    };
+
     // [MAIN] Primary rule to generate the struct
     (
         $(#[$meta:meta])*
@@ -9,31 +31,196 @@ macro_rules! nested_struct {
             $(
                 $(#[$field_meta:meta])*
                 $(@nested(#[$field_nested_meta:meta]))*
-                $field_vis:vis $field_name:ident : $field_ty:ident $({
+                $(@nested($field_nested_vis:vis))?
+                $field_vis:vis $field_name:ident : $($field_ty:ident)? $(enum {
+                    $($field_ty_enum_inner:tt)*
+                })? $({
                     $($field_ty_inner:tt)*
-                })?
+                })? $((
+                    $($field_ty_tuple_inner:tt)*
+                ))?
             ),*
         $(,)? }
     ) => {
-        // Generate our primary struct
+        // Generate our primary struct. When a field omits its type, `@field_ty` synthesizes
+        // one from the field's own name ("type is definition").
         $(#[$meta])* $vis struct $name {
             $(
                 $(#[$field_meta])*
-                $field_vis $field_name : $field_ty
+                $field_vis $field_name : nested_struct!(@field_ty $field_name $($field_ty)?)
             ),*
         }
 
-        // Generate our inner structs for fields
+        // Generate our inner structs/enums for fields. A field's own visibility controls the
+        // field declaration above; `@nested(...)` optionally controls the generated type's
+        // visibility separately, defaulting to the field's visibility when omitted.
         $(nested_struct! {
-            @nested
+            @nested_field
             $(#[$field_nested_meta])*
-            $field_vis $field_ty $({
+            $(@nested($field_nested_vis))?
+            $field_vis $field_name : $($field_ty)? $(enum {
+                $($field_ty_enum_inner)*
+            })? $({
                 $($field_ty_inner)*
-            })?
+            })? $((
+                $($field_ty_tuple_inner)*
+            ))?
         })*
     };
 
-    // [INCLUDE] Used to filter out struct generation to only nested types
+    // [FIELD TYPE] Resolve a field's type: the explicit type if one was given, otherwise a
+    // PascalCase name synthesized from the field's own identifier
+    (@field_ty $field_name:ident $field_ty:ident) => { $field_ty };
+    (@field_ty $field_name:ident) => {
+        paste::paste! { [<$field_name:camel>] }
+    };
+
+    // [NESTED FIELD DISPATCH - NAMED, VIS OVERRIDE] An explicit type name was given along with
+    // `@nested($vis)` - the generated type uses that visibility instead of the field's own
+    (
+        @nested_field
+        $(#[$field_nested_meta:meta])*
+        @nested($field_nested_vis:vis)
+        $field_vis:vis $field_name:ident : $field_ty:ident $(enum {
+            $($field_ty_enum_inner:tt)*
+        })? $({
+            $($field_ty_inner:tt)*
+        })? $((
+            $($field_ty_tuple_inner:tt)*
+        ))?
+    ) => {
+        nested_struct! {
+            @nested
+            $(#[$field_nested_meta])*
+            $field_nested_vis $field_ty $(enum {
+                $($field_ty_enum_inner)*
+            })? $({
+                $($field_ty_inner)*
+            })? $((
+                $($field_ty_tuple_inner)*
+            ))?
+        }
+    };
+
+    // [NESTED FIELD DISPATCH - NAMED] An explicit type name was given - forward to @nested as-is,
+    // with the generated type inheriting the field's own visibility
+    (
+        @nested_field
+        $(#[$field_nested_meta:meta])*
+        $field_vis:vis $field_name:ident : $field_ty:ident $(enum {
+            $($field_ty_enum_inner:tt)*
+        })? $({
+            $($field_ty_inner:tt)*
+        })? $((
+            $($field_ty_tuple_inner:tt)*
+        ))?
+    ) => {
+        nested_struct! {
+            @nested
+            $(#[$field_nested_meta])*
+            $field_vis $field_ty $(enum {
+                $($field_ty_enum_inner)*
+            })? $({
+                $($field_ty_inner)*
+            })? $((
+                $($field_ty_tuple_inner)*
+            ))?
+        }
+    };
+
+    // [NESTED FIELD DISPATCH - ANONYMOUS, VIS OVERRIDE] No type name was given, but
+    // `@nested($vis)` sets the synthesized type's visibility independently of the field's own
+    (
+        @nested_field
+        $(#[$field_nested_meta:meta])*
+        @nested($field_nested_vis:vis)
+        $field_vis:vis $field_name:ident : {
+            $($field_ty_inner:tt)*
+        }
+    ) => {
+        paste::paste! {
+            nested_struct! {
+                @nested
+                $(#[$field_nested_meta])*
+                $field_nested_vis [<$field_name:camel>] {
+                    $($field_ty_inner)*
+                }
+            }
+        }
+    };
+
+    // [NESTED FIELD DISPATCH - ANONYMOUS] No type name was given - synthesize one from the
+    // field's identifier (PascalCase), then forward to @nested, inheriting the field's own
+    // visibility.
+    //
+    // Note: this and [ANONYMOUS TUPLE] below only cover the brace (struct) and tuple bodies.
+    // An anonymous `enum` body (`field: enum { ... }`, with no type name) can't be added here -
+    // the bare `enum` keyword is itself a valid `ident` token, so a macro_rules matcher that
+    // makes the type name optional immediately before a literal `enum` is genuinely ambiguous
+    // to the compiler ("built-in NTs ident ... or 1 other option"), not just to a reader. An
+    // enum field's type name must be given explicitly.
+    (
+        @nested_field
+        $(#[$field_nested_meta:meta])*
+        $field_vis:vis $field_name:ident : {
+            $($field_ty_inner:tt)*
+        }
+    ) => {
+        paste::paste! {
+            nested_struct! {
+                @nested
+                $(#[$field_nested_meta])*
+                $field_vis [<$field_name:camel>] {
+                    $($field_ty_inner)*
+                }
+            }
+        }
+    };
+
+    // [NESTED FIELD DISPATCH - ANONYMOUS TUPLE, VIS OVERRIDE] No type name was given for a
+    // tuple-style body, but `@nested($vis)` sets the synthesized type's visibility
+    // independently of the field's own
+    (
+        @nested_field
+        $(#[$field_nested_meta:meta])*
+        @nested($field_nested_vis:vis)
+        $field_vis:vis $field_name:ident : (
+            $($field_ty_tuple_inner:tt)*
+        )
+    ) => {
+        paste::paste! {
+            nested_struct! {
+                @nested
+                $(#[$field_nested_meta])*
+                $field_nested_vis [<$field_name:camel>] (
+                    $($field_ty_tuple_inner)*
+                )
+            }
+        }
+    };
+
+    // [NESTED FIELD DISPATCH - ANONYMOUS TUPLE] No type name was given for a tuple-style
+    // body - synthesize one from the field's identifier (PascalCase), then forward to @nested,
+    // inheriting the field's own visibility
+    (
+        @nested_field
+        $(#[$field_nested_meta:meta])*
+        $field_vis:vis $field_name:ident : (
+            $($field_ty_tuple_inner:tt)*
+        )
+    ) => {
+        paste::paste! {
+            nested_struct! {
+                @nested
+                $(#[$field_nested_meta])*
+                $field_vis [<$field_name:camel>] (
+                    $($field_ty_tuple_inner)*
+                )
+            }
+        }
+    };
+
+    // [STRUCT INCLUDE] Used to filter out struct generation to only nested types
     (@nested $(#[$meta:meta])* $vis:vis $name:ident {$($fields:tt)*}) => {
         nested_struct! {
             $(#[$meta])*
@@ -43,9 +230,221 @@ macro_rules! nested_struct {
         }
     };
 
-    // [EXCLUDE] Used to filter out struct generation to only nested types
+    // [TUPLE STRUCT INCLUDE] Used to filter out tuple struct generation to only nested types
+    (
+        @nested
+        $(#[$meta:meta])* $vis:vis $name:ident (
+            $(
+                $(#[$elem_meta:meta])*
+                $(@nested(#[$elem_nested_meta:meta]))*
+                $elem_vis:vis $elem_ty:ident $(enum {
+                    $($elem_ty_enum_inner:tt)*
+                })? $({
+                    $($elem_ty_inner:tt)*
+                })? $((
+                    $($elem_ty_tuple_inner:tt)*
+                ))?
+            ),*
+        $(,)? )
+    ) => {
+        // Generate our nested tuple struct
+        $(#[$meta])* $vis struct $name (
+            $(
+                $(#[$elem_meta])*
+                $elem_vis $elem_ty
+            ),*
+        );
+
+        // Generate our inner structs/enums for tuple elements
+        $(nested_struct! {
+            @nested
+            $(#[$elem_nested_meta])*
+            $elem_vis $elem_ty $(enum {
+                $($elem_ty_enum_inner)*
+            })? $({
+                $($elem_ty_inner)*
+            })? $((
+                $($elem_ty_tuple_inner)*
+            ))?
+        })*
+    };
+
+    // [ENUM INCLUDE] Used to filter out enum generation to only nested types
+    (
+        @nested
+        $(#[$meta:meta])* $vis:vis $name:ident enum {
+            $(
+                $variant_name:ident $({
+                    $(
+                        $(#[$variant_field_meta:meta])*
+                        $(@nested(#[$variant_field_nested_meta:meta]))*
+                        $variant_field_vis:vis $variant_field_name:ident : $variant_field_ty:ident $(enum {
+                            $($variant_field_ty_enum_inner:tt)*
+                        })? $({
+                            $($variant_field_ty_inner:tt)*
+                        })?
+                    ),*
+                $(,)? })? $((
+                    $(
+                        $variant_elem_vis:vis $variant_elem_ty:ident $(enum {
+                            $($variant_elem_ty_enum_inner:tt)*
+                        })? $({
+                            $($variant_elem_ty_inner:tt)*
+                        })?
+                    ),*
+                $(,)? ))?
+            ),*
+        $(,)? }
+    ) => {
+        // Generate our nested enum
+        $(#[$meta])* $vis enum $name {
+            $(
+                $variant_name $({
+                    $(
+                        $(#[$variant_field_meta])*
+                        $variant_field_vis $variant_field_name : $variant_field_ty
+                    ),*
+                })? $((
+                    $(
+                        $variant_elem_vis $variant_elem_ty
+                    ),*
+                ))?
+            ),*
+        }
+
+        // Generate our inner structs/enums for struct-style variant fields
+        $($(
+            $(nested_struct! {
+                @nested
+                $(#[$variant_field_nested_meta])*
+                $variant_field_vis $variant_field_ty $(enum {
+                    $($variant_field_ty_enum_inner)*
+                })? $({
+                    $($variant_field_ty_inner)*
+                })?
+            })*
+        )?)*
+
+        // Generate our inner structs/enums for tuple-style variant elements
+        $($(
+            $(nested_struct! {
+                @nested
+                $variant_elem_vis $variant_elem_ty $(enum {
+                    $($variant_elem_ty_enum_inner)*
+                })? $({
+                    $($variant_elem_ty_inner)*
+                })?
+            })*
+        )?)*
+    };
+
+    // [EXCLUDE] Used to filter out struct/enum generation to only nested types
     (@nested $(#[$meta:meta])* $vis:vis $name:ident) => {};
 
+    // [ACCESSORS] Walk every field in a struct body, dispatching each one to @accessor_field.
+    // `$path` is kept as a single opaque token tree (the `[...]` list) rather than being
+    // destructured into its own `$(...)*` here - doing so would make it an independently
+    // repeating metavariable, and macro_rules can't zip two independently repeating
+    // metavariables (`$path` and `$field_name`) together in the same output repetition.
+    (
+        @accessors $root:ident $path:tt {
+            $(
+                $(#[$field_meta:meta])*
+                $(@nested(#[$field_nested_meta:meta]))*
+                $(@nested($field_nested_vis:vis))?
+                $field_vis:vis $field_name:ident : $($field_ty:ident)? $(enum {
+                    $($field_ty_enum_inner:tt)*
+                })? $({
+                    $($field_ty_inner:tt)*
+                })? $((
+                    $($field_ty_tuple_inner:tt)*
+                ))?
+            ),*
+        $(,)? }
+    ) => {
+        $(nested_struct! {
+            @accessor_field $root $path $field_vis $field_name : $($field_ty)? $(enum {
+                $($field_ty_enum_inner)*
+            })? $({
+                $($field_ty_inner)*
+            })? $((
+                $($field_ty_tuple_inner)*
+            ))?
+        })*
+    };
+
+    // [ACCESSOR FIELD - NESTED] A plain nested struct field - recurse, extending the path
+    (
+        @accessor_field $root:ident [$($path:ident)*] $field_vis:vis $field_name:ident : $field_ty:ident {
+            $($field_ty_inner:tt)*
+        }
+    ) => {
+        nested_struct! {
+            @accessors $root [$($path)* $field_name] { $($field_ty_inner)* }
+        }
+    };
+
+    // [ACCESSOR FIELD - ANONYMOUS NESTED] No type name was given - the nested type's name is
+    // not needed to build the path, so recurse exactly as for a named nested struct field
+    (
+        @accessor_field $root:ident [$($path:ident)*] $field_vis:vis $field_name:ident : {
+            $($field_ty_inner:tt)*
+        }
+    ) => {
+        paste::paste! {
+            nested_struct! {
+                @accessors $root [$($path)* $field_name] { $($field_ty_inner)* }
+            }
+        }
+    };
+
+    // [ACCESSOR FIELD - LEAF] An enum, tuple struct, or regular field - treated as a leaf
+    (@accessor_field $root:ident [$($path:ident)*] $field_vis:vis $field_name:ident : $field_ty:ident enum {$($field_ty_inner:tt)*}) => {
+        nested_struct! { @accessor_method $root [$($path)*] $field_vis $field_name : $field_ty }
+    };
+    (@accessor_field $root:ident [$($path:ident)*] $field_vis:vis $field_name:ident : $field_ty:ident ($($field_ty_inner:tt)*)) => {
+        nested_struct! { @accessor_method $root [$($path)*] $field_vis $field_name : $field_ty }
+    };
+    (@accessor_field $root:ident [$($path:ident)*] $field_vis:vis $field_name:ident : $field_ty:ident) => {
+        nested_struct! { @accessor_method $root [$($path)*] $field_vis $field_name : $field_ty }
+    };
+
+    // [ACCESSOR FIELD - ANONYMOUS LEAF] No type name was given and the field is not a nested
+    // struct (an enum body, a tuple body, or no body at all) - synthesize the leaf's type name
+    // from the field's identifier (PascalCase)
+    (@accessor_field $root:ident [$($path:ident)*] $field_vis:vis $field_name:ident : enum {$($field_ty_inner:tt)*}) => {
+        paste::paste! {
+            nested_struct! { @accessor_method $root [$($path)*] $field_vis $field_name : [<$field_name:camel>] }
+        }
+    };
+    (@accessor_field $root:ident [$($path:ident)*] $field_vis:vis $field_name:ident : ($($field_ty_inner:tt)*)) => {
+        paste::paste! {
+            nested_struct! { @accessor_method $root [$($path)*] $field_vis $field_name : [<$field_name:camel>] }
+        }
+    };
+    (@accessor_field $root:ident [$($path:ident)*] $field_vis:vis $field_name:ident :) => {
+        paste::paste! {
+            nested_struct! { @accessor_method $root [$($path)*] $field_vis $field_name : [<$field_name:camel>] }
+        }
+    };
+
+    // [ACCESSOR METHOD] Emit the `&self` / `&mut self` accessor pair for a single leaf field,
+    // naming each method by joining its path (outer-to-inner field names) with `_`, and
+    // visible according to the leaf field's own declared visibility
+    (@accessor_method $root:ident [$($path:ident)*] $field_vis:vis $field_name:ident : $field_ty:ident) => {
+        paste::paste! {
+            impl $root {
+                $field_vis fn [<$($path _)* $field_name>](&self) -> &$field_ty {
+                    &self.$($path.)* $field_name
+                }
+
+                $field_vis fn [<$($path _)* $field_name _mut>](&mut self) -> &mut $field_ty {
+                    &mut self.$($path.)* $field_name
+                }
+            }
+        }
+    };
+
     // Any garbage we will ignore, including generating an invalid struct
     /* ($($other:tt)*) => {
         compile_error!(stringify!($($other)*));
@@ -153,4 +552,277 @@ mod tests {
             };
         }
     }
+
+    #[test]
+    #[allow(dead_code)]
+    fn supports_deep_field_path_accessors() {
+        nested_struct! {
+            @accessors
+            struct TestStruct {
+                field: u32,
+                a: A {
+                    b: B {
+                        value: u32
+                    }
+                }
+            }
+        }
+
+        let mut test_struct = TestStruct {
+            field: 123,
+            a: A {
+                b: B { value: 456 },
+            },
+        };
+
+        assert_eq!(*test_struct.field(), 123);
+        assert_eq!(*test_struct.a_b_value(), 456);
+
+        *test_struct.a_b_value_mut() = 789;
+        assert_eq!(test_struct.a.b.value, 789);
+    }
+
+    #[test]
+    #[allow(dead_code)]
+    fn accessor_visibility_matches_the_field_s_own_visibility() {
+        mod inner {
+            nested_struct! {
+                @accessors
+                pub struct TestStruct {
+                    pub field: u32,
+                    pub(crate) a: A {
+                        pub(crate) b: B {
+                            pub(crate) value: u32
+                        }
+                    }
+                }
+            }
+        }
+
+        let mut test_struct = inner::TestStruct {
+            field: 123,
+            a: inner::A {
+                b: inner::B { value: 456 },
+            },
+        };
+
+        assert_eq!(*test_struct.field(), 123);
+        assert_eq!(*test_struct.a_b_value(), 456);
+
+        *test_struct.a_b_value_mut() = 789;
+        assert_eq!(test_struct.a.b.value, 789);
+    }
+
+    #[test]
+    #[allow(dead_code)]
+    fn supports_deep_field_path_accessors_with_anonymous_nested_fields() {
+        nested_struct! {
+            @accessors
+            struct TestStruct {
+                a: {
+                    value: u32
+                }
+            }
+        }
+
+        let mut test_struct = TestStruct {
+            a: A { value: 123 },
+        };
+
+        assert_eq!(*test_struct.a_value(), 123);
+
+        *test_struct.a_value_mut() = 456;
+        assert_eq!(test_struct.a.value, 456);
+    }
+
+    #[test]
+    #[allow(dead_code)]
+    fn supports_deep_field_path_accessors_with_vis_overridden_nested_fields() {
+        nested_struct! {
+            @accessors
+            struct TestStruct {
+                @nested(pub(crate))
+                a: A {
+                    value: u32
+                }
+            }
+        }
+
+        let mut test_struct = TestStruct {
+            a: A { value: 123 },
+        };
+
+        assert_eq!(*test_struct.a_value(), 123);
+
+        *test_struct.a_value_mut() = 456;
+        assert_eq!(test_struct.a.value, 456);
+    }
+
+    #[test]
+    #[allow(dead_code)]
+    fn supports_independent_visibility_for_nested_types() {
+        mod inner {
+            nested_struct! {
+                pub struct TestStruct {
+                    @nested(pub(crate))
+                    pub field: NestedField {
+                        pub field: u32
+                    }
+                }
+            }
+
+            pub(crate) fn make() -> TestStruct {
+                TestStruct {
+                    field: NestedField { field: 123 },
+                }
+            }
+        }
+
+        let test_struct = inner::make();
+        assert_eq!(test_struct.field.field, 123);
+    }
+
+    #[test]
+    #[allow(dead_code)]
+    fn supports_named_struct_with_anonymous_nested_fields() {
+        nested_struct! {
+            struct TestStruct {
+                address: {
+                    street: String
+                }
+            }
+        }
+
+        let _ = TestStruct {
+            address: Address {
+                street: "123 Main St".to_string(),
+            },
+        };
+    }
+
+    #[test]
+    #[allow(dead_code)]
+    fn supports_named_struct_with_deeply_anonymous_nested_fields() {
+        nested_struct! {
+            struct TestStruct {
+                address: {
+                    geo: {
+                        lat: f64
+                    }
+                }
+            }
+        }
+
+        let _ = TestStruct {
+            address: Address {
+                geo: Geo { lat: 1.0 },
+            },
+        };
+    }
+
+    #[test]
+    #[allow(dead_code)]
+    fn supports_named_struct_with_nested_tuple_struct_fields() {
+        nested_struct! {
+            struct TestStruct {
+                field: Point(f32, f32)
+            }
+        }
+
+        let _ = TestStruct {
+            field: Point(1.0, 2.0),
+        };
+    }
+
+    #[test]
+    #[allow(dead_code)]
+    fn supports_named_struct_with_deeply_nested_tuple_struct_fields() {
+        nested_struct! {
+            struct TestStruct {
+                field: Wrapper(Inner { x: u32 }, String)
+            }
+        }
+
+        let _ = TestStruct {
+            field: Wrapper(Inner { x: 123 }, "label".to_string()),
+        };
+    }
+
+    #[test]
+    #[allow(dead_code)]
+    fn supports_named_struct_with_anonymous_nested_tuple_struct_fields() {
+        nested_struct! {
+            struct TestStruct {
+                field: (f32, f32)
+            }
+        }
+
+        let _ = TestStruct {
+            field: Field(1.0, 2.0),
+        };
+    }
+
+    #[test]
+    #[allow(dead_code)]
+    fn supports_named_struct_with_nested_enum_fields() {
+        nested_struct! {
+            struct TestStruct {
+                field: Status enum {
+                    Active,
+                    Pending,
+                    Failed { code: u32, message: String }
+                }
+            }
+        }
+
+        let _ = TestStruct {
+            field: Status::Failed {
+                code: 123,
+                message: "oops".to_string(),
+            },
+        };
+    }
+
+    #[test]
+    #[allow(dead_code)]
+    fn supports_named_struct_with_deeply_nested_enum_fields() {
+        nested_struct! {
+            struct TestStruct {
+                field: Status enum {
+                    Active,
+                    Failed {
+                        reason: Reason enum {
+                            Timeout,
+                            Other { message: String }
+                        }
+                    }
+                }
+            }
+        }
+
+        let _ = TestStruct {
+            field: Status::Failed {
+                reason: Reason::Other {
+                    message: "oops".to_string(),
+                },
+            },
+        };
+    }
+
+    #[test]
+    #[allow(dead_code)]
+    fn supports_named_struct_with_nested_enum_tuple_variant_fields() {
+        nested_struct! {
+            struct TestStruct {
+                field: Status enum {
+                    Active,
+                    Failed(Code { value: u32 })
+                }
+            }
+        }
+
+        let _ = TestStruct {
+            field: Status::Failed(Code { value: 123 }),
+        };
+    }
 }